@@ -1,27 +1,99 @@
 //! Data models.
+use std::fmt;
+
+use argon2::password_hash::{rand_core::OsRng, PasswordHasher, SaltString};
+use argon2::Argon2;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::OtterError;
+
+/// Allowed user names: alphanumeric plus underscore, 3-32 characters.
+static NAME_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[A-Za-z0-9_]{3,32}$").unwrap());
+
+/// A rejected `create_user` call: the supplied name didn't pass validation.
+#[derive(Debug)]
+pub struct ValidationError {
+    message: String,
+}
+
+impl ValidationError {
+    fn new(message: impl Into<String>) -> Self {
+        ValidationError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "validation error: {}", self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
 
 /// User model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
+    pub id: String,
     pub name: String,
+    pub password_hash: String,
 }
 
 impl User {
-    /// Create a new user.
-    pub fn new(name: String) -> Self {
-        User { name }
+    /// Build a user from already-known fields, e.g. when hydrating from
+    /// storage. Prefer [`create_user`] for registering a brand-new user.
+    pub fn new(id: String, name: String, password_hash: String) -> Self {
+        User {
+            id,
+            name,
+            password_hash,
+        }
     }
-    
+
     /// Greet the user.
     pub fn greet(&self) -> String {
         format!("Hello, {}!", self.name)
     }
 }
 
-/// Factory function for creating users.
-pub fn create_user(name: &str) -> User {
-    User::new(name.to_string())
+/// Validate a candidate user name against [`NAME_PATTERN`].
+fn validate_name(name: &str) -> Result<(), ValidationError> {
+    if NAME_PATTERN.is_match(name) {
+        Ok(())
+    } else {
+        Err(ValidationError::new(format!(
+            "name must be 3-32 alphanumeric/underscore characters, got {name:?}"
+        )))
+    }
+}
+
+/// Hash a plaintext password with Argon2, never returning or storing the
+/// plaintext itself.
+fn hash_password(password: &str) -> Result<String, OtterError> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|err| OtterError::Hashing(err.to_string()))?;
+    Ok(hash.to_string())
+}
+
+/// Registration entry point: validates `name`, hashes `password`, and
+/// returns a brand-new `User` on success.
+pub fn create_user(name: &str, password: &str) -> Result<User, OtterError> {
+    tracing::debug!(name, "create_user: enter");
+    validate_name(name)?;
+    let user = User::new(
+        Uuid::new_v4().to_string(),
+        name.to_string(),
+        hash_password(password)?,
+    );
+    tracing::debug!(id = %user.id, "create_user: exit");
+    Ok(user)
 }
 
 /// Module constant.
 pub const DEFAULT_NAME: &str = "Guest";
-
@@ -0,0 +1,100 @@
+//! HTTP API mounting `UserService`, gated behind the `server` feature.
+#![cfg(feature = "server")]
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::OtterError;
+use crate::models::User;
+use crate::services::UserService;
+
+/// Body accepted by `POST /users`. `password` is optional and defaults to
+/// an empty password when omitted, so a spec-shaped `{ "name": ... }` body
+/// is still accepted.
+#[derive(Deserialize)]
+struct CreateUserRequest {
+    name: String,
+    #[serde(default)]
+    password: Option<String>,
+}
+
+/// Public view of a `User`: never includes `password_hash`.
+#[derive(Serialize)]
+struct UserResponse {
+    id: String,
+    name: String,
+}
+
+impl From<User> for UserResponse {
+    fn from(user: User) -> Self {
+        UserResponse {
+            id: user.id,
+            name: user.name,
+        }
+    }
+}
+
+/// Mount `UserService` behind `/users` routes.
+pub fn build_router(service: Arc<UserService>) -> Router {
+    Router::new()
+        .route("/users", post(create_user))
+        .route("/users/:id", get(get_user))
+        .route("/users/:id/greeting", get(get_greeting))
+        .with_state(service)
+}
+
+async fn create_user(
+    State(service): State<Arc<UserService>>,
+    Json(body): Json<CreateUserRequest>,
+) -> Response {
+    let password = body.password.as_deref().unwrap_or("");
+    match service.create_user(&body.name, password) {
+        Ok(user) => (StatusCode::CREATED, Json(UserResponse::from(user))).into_response(),
+        Err(err) => error_response(err),
+    }
+}
+
+async fn get_user(State(service): State<Arc<UserService>>, Path(id): Path<String>) -> Response {
+    match service.get_user(&id) {
+        Ok(user) => Json(UserResponse::from(user)).into_response(),
+        Err(err) => error_response(err),
+    }
+}
+
+async fn get_greeting(
+    State(service): State<Arc<UserService>>,
+    Path(id): Path<String>,
+) -> Response {
+    match service.get_user(&id) {
+        Ok(user) => user.greet().into_response(),
+        Err(err) => error_response(err),
+    }
+}
+
+/// Map an `OtterError` to the HTTP response callers should see.
+///
+/// `NotFound`/`Validation` messages are safe to echo back as-is. `Storage`
+/// and `Hashing` wrap internal details (filesystem paths, library errors)
+/// that callers have no use for, so those get a generic body instead of
+/// `err.to_string()`.
+fn error_response(err: OtterError) -> Response {
+    match err {
+        OtterError::NotFound(_) | OtterError::Validation(_) => {
+            let status = if matches!(err, OtterError::NotFound(_)) {
+                StatusCode::NOT_FOUND
+            } else {
+                StatusCode::BAD_REQUEST
+            };
+            (status, err.to_string()).into_response()
+        }
+        OtterError::Storage(_) | OtterError::Hashing(_) => {
+            tracing::error!(%err, "internal error handling request");
+            (StatusCode::INTERNAL_SERVER_ERROR, "internal server error").into_response()
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! A lightweight dependency-injection container for wiring services.
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Error returned when a `Container` cannot resolve a requested type.
+#[derive(Debug)]
+pub struct ResolveError {
+    type_name: &'static str,
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no binding registered for `{}`", self.type_name)
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+type Factory = Box<dyn Fn(&Container) -> Box<dyn Any> >;
+
+/// A minimal service container: register bindings once, then resolve them
+/// (and their dependencies) by type.
+#[derive(Default)]
+pub struct Container {
+    factories: HashMap<TypeId, Factory>,
+}
+
+impl Container {
+    /// Create an empty container.
+    pub fn new() -> Self {
+        Container {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Start a binding for `T`, to be completed with `.to_factory(...)`.
+    pub fn bind<T: 'static>(&mut self) -> Binding<'_, T> {
+        Binding {
+            container: self,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Resolve a previously bound `T`, recursively resolving its
+    /// dependencies along the way.
+    pub fn resolve<T: 'static>(&self) -> Result<T, ResolveError> {
+        let type_id = TypeId::of::<T>();
+        let factory = self.factories.get(&type_id).ok_or(ResolveError {
+            type_name: std::any::type_name::<T>(),
+        })?;
+        let boxed = factory(self);
+        Ok(*boxed
+            .downcast::<T>()
+            .expect("factory produced a value of the wrong type"))
+    }
+}
+
+/// In-progress binding for `T`, returned by [`Container::bind`].
+pub struct Binding<'a, T> {
+    container: &'a mut Container,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: 'static> Binding<'a, T> {
+    /// Bind `T` to a factory closure, which may itself resolve further
+    /// dependencies from the container.
+    pub fn to_factory<F>(self, factory: F)
+    where
+        F: Fn(&Container) -> T + 'static,
+    {
+        self.container.factories.insert(
+            TypeId::of::<T>(),
+            Box::new(move |container| Box::new(factory(container))),
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Crate-wide error type.
+use std::fmt;
+
+use crate::models::ValidationError;
+
+/// The error type returned by fallible APIs across the crate.
+#[derive(Debug)]
+pub enum OtterError {
+    /// A lookup (e.g. by id) found nothing.
+    NotFound(String),
+    /// Input failed validation before it could be used.
+    Validation(ValidationError),
+    /// Reading or writing the backing store failed.
+    Storage(String),
+    /// Hashing a password failed.
+    Hashing(String),
+}
+
+impl fmt::Display for OtterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OtterError::NotFound(what) => write!(f, "not found: {what}"),
+            OtterError::Validation(err) => write!(f, "{err}"),
+            OtterError::Storage(msg) => write!(f, "storage error: {msg}"),
+            OtterError::Hashing(msg) => write!(f, "hashing error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for OtterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OtterError::Validation(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ValidationError> for OtterError {
+    fn from(err: ValidationError) -> Self {
+        OtterError::Validation(err)
+    }
+}
+
+impl From<serde_json::Error> for OtterError {
+    fn from(err: serde_json::Error) -> Self {
+        OtterError::Storage(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for OtterError {
+    fn from(err: std::io::Error) -> Self {
+        OtterError::Storage(err.to_string())
+    }
+}
@@ -1,23 +1,79 @@
 //! Main module.
+mod api;
+mod di;
+mod errors;
 mod models;
 mod services;
+mod telemetry;
 
-use models::{User, create_user};
+use std::sync::Arc;
+
+use di::Container;
+use models::{create_user, User};
+use services::repository::{JsonFileRepository, UserRepository};
 use services::UserService;
 
+/// Register the bindings the crate needs and return the wired container.
+fn build_container() -> Container {
+    let mut container = Container::new();
+    container.bind::<Arc<dyn UserRepository>>().to_factory(|_| {
+        let repository =
+            JsonFileRepository::new("./data").expect("failed to open ./data/users.json");
+        Arc::new(repository) as Arc<dyn UserRepository>
+    });
+    container.bind::<UserService>().to_factory(|container| {
+        let repository = container
+            .resolve::<Arc<dyn UserRepository>>()
+            .expect("UserRepository binding is registered above");
+        UserService::new(repository)
+    });
+    container
+}
+
+/// Serve `UserService` over HTTP until the process is killed.
+#[cfg(feature = "server")]
+fn run_server(service: Arc<UserService>) {
+    let runtime = tokio::runtime::Runtime::new().expect("failed to start tokio runtime");
+    runtime.block_on(async {
+        let app = api::build_router(service);
+        let listener = tokio::net::TcpListener::bind("0.0.0.0:3000")
+            .await
+            .expect("failed to bind 0.0.0.0:3000");
+        axum::serve(listener, app)
+            .await
+            .expect("server error");
+    });
+}
+
 fn main() {
+    #[cfg(feature = "telemetry")]
+    telemetry::init();
+
     // Create user directly
-    let user1 = User::new("Bob".to_string());
+    let user1 = User::new("bob-1".to_string(), "Bob".to_string(), String::new());
     println!("{}", user1.greet());
-    
+
     // Create user via factory
-    let user2 = create_user("Charlie");
+    let user2 = create_user("Charlie", "hunter2-change-me").expect("valid name");
     println!("{}", user2.greet());
-    
+
     // Use service
-    let service = UserService;
-    let user3 = service.get_user();
+    let container = build_container();
+    let service = container
+        .resolve::<UserService>()
+        .expect("UserService binding is registered in build_container");
+    let user3 = service
+        .create_user("Alice", "hunter2-change-me")
+        .expect("valid name");
     let result = service.process_user(&user3);
     println!("{}", result);
+
+    match service.get_user(&user3.id) {
+        Ok(found) => println!("looked up: {}", found.greet()),
+        Err(err) => println!("lookup failed: {err}"),
+    }
+
+    #[cfg(feature = "server")]
+    run_server(Arc::new(service));
 }
 
@@ -1,15 +1,54 @@
 //! Business logic.
-use crate::models::{User, create_user};
+pub mod repository;
 
-pub struct UserService;
+use std::sync::Arc;
+
+use argon2::{Argon2, PasswordHash, PasswordVerifier};
+
+use crate::errors::OtterError;
+use crate::models::{create_user, User};
+use repository::UserRepository;
+
+pub struct UserService {
+    repository: Arc<dyn UserRepository>,
+}
 
 impl UserService {
-    /// Get a user.
-    pub fn get_user(&self) -> User {
-        create_user("Alice")
+    /// Build a service backed by the given repository.
+    pub fn new(repository: Arc<dyn UserRepository>) -> Self {
+        UserService { repository }
+    }
+
+    /// Get a user by id.
+    pub fn get_user(&self, id: &str) -> Result<User, OtterError> {
+        self.repository
+            .find_by_id(id)
+            .ok_or_else(|| OtterError::NotFound(format!("user {id}")))
     }
-    
+
+    /// Register a new user and persist it.
+    pub fn create_user(&self, name: &str, password: &str) -> Result<User, OtterError> {
+        let user = create_user(name, password)?;
+        self.repository.create(user.id.clone(), user.clone())?;
+        Ok(user)
+    }
+
+    /// Check a plaintext password against a user's stored hash.
+    pub fn verify_password(&self, user: &User, candidate: &str) -> bool {
+        let Ok(hash) = PasswordHash::new(&user.password_hash) else {
+            return false;
+        };
+        Argon2::default()
+            .verify_password(candidate.as_bytes(), &hash)
+            .is_ok()
+    }
+
     /// Process a user.
+    ///
+    /// `User::greet` can't fail, so this stays infallible; fallibility
+    /// for a user-facing operation belongs on the operation that can
+    /// actually fail, which is [`UserService::create_user`].
+    #[tracing::instrument(skip(self, user), fields(name = %user.name))]
     pub fn process_user(&self, user: &User) -> String {
         user.greet()
     }
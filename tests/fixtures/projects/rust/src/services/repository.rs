@@ -0,0 +1,101 @@
+//! Persistence for service-layer models.
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::errors::OtterError;
+use crate::models::User;
+
+/// A repository for looking up and mutating `User` records.
+///
+/// Implementations are expected to be safe to share behind an `Arc`, so
+/// mutating methods take `&self` and rely on interior mutability.
+pub trait UserRepository: Send + Sync {
+    /// Insert a new user under `id`.
+    fn create(&self, id: String, user: User) -> Result<(), OtterError>;
+
+    /// Look up a user by id.
+    fn find_by_id(&self, id: &str) -> Option<User>;
+
+    /// Return every stored user.
+    fn all(&self) -> Vec<User>;
+
+    /// Remove a user by id.
+    fn delete(&self, id: &str) -> Result<(), OtterError>;
+}
+
+/// A `UserRepository` backed by a single JSON file on disk.
+///
+/// The whole file is loaded into an in-memory map on construction, and
+/// flushed back to disk after every mutating call.
+pub struct JsonFileRepository {
+    path: PathBuf,
+    users: Mutex<HashMap<String, User>>,
+}
+
+impl JsonFileRepository {
+    /// Open the `users.json` file under `base_dir`, creating an empty
+    /// store if it doesn't exist yet.
+    ///
+    /// A missing file is treated as an empty store; a present-but-corrupt
+    /// or unreadable file is a `Storage` error rather than being silently
+    /// discarded (the next mutating call would otherwise overwrite it).
+    pub fn new(base_dir: impl AsRef<Path>) -> Result<Self, OtterError> {
+        let path = base_dir.as_ref().join("users.json");
+        let users = Mutex::new(load(&path)?);
+        Ok(JsonFileRepository { path, users })
+    }
+
+    fn flush(&self, users: &HashMap<String, User>) -> Result<(), OtterError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(users)?;
+        // Write to a temp file and rename so a crash mid-write can't leave
+        // `users.json` half-written.
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, json)?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+impl UserRepository for JsonFileRepository {
+    fn create(&self, id: String, user: User) -> Result<(), OtterError> {
+        let mut users = self.users.lock().expect("users mutex poisoned");
+        users.insert(id, user);
+        self.flush(&users)
+    }
+
+    fn find_by_id(&self, id: &str) -> Option<User> {
+        self.users.lock().expect("users mutex poisoned").get(id).cloned()
+    }
+
+    fn all(&self) -> Vec<User> {
+        self.users
+            .lock()
+            .expect("users mutex poisoned")
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn delete(&self, id: &str) -> Result<(), OtterError> {
+        let mut users = self.users.lock().expect("users mutex poisoned");
+        users.remove(id);
+        self.flush(&users)
+    }
+}
+
+/// Load `T` from `path`, treating a missing file as `T::default()` and
+/// propagating any other read or parse failure as a `Storage` error.
+fn load<T: DeserializeOwned + Default>(path: &Path) -> Result<T, OtterError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(serde_json::from_str(&contents)?),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err.into()),
+    }
+}
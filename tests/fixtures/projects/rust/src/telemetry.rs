@@ -0,0 +1,17 @@
+//! Optional structured logging for the crate, gated behind the
+//! `telemetry` feature so library users who don't want it pay nothing.
+#![cfg(feature = "telemetry")]
+
+/// Install a `tracing_subscriber` fmt layer reading verbosity from
+/// `RUST_LOG` (falling back to `info`).
+///
+/// Call this once, near the start of `main`, before using any
+/// instrumented APIs.
+pub fn init() {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+}